@@ -22,10 +22,15 @@ use crate::constants::PARAMETER_COUNT_3DE_ANAMORPHIC_STD_DEG4;
 use crate::constants::PARAMETER_COUNT_3DE_ANAMORPHIC_STD_DEG4_RESCALED;
 use crate::constants::PARAMETER_COUNT_3DE_CLASSIC;
 use crate::constants::PARAMETER_COUNT_3DE_RADIAL_STD_DEG4;
+use crate::constants::PARAMETER_COUNT_OPENCV_BROWN_CONRADY;
 use crate::constants::STATIC_FRAME_NUMBER;
 use crate::cxxbridge::ffi::CameraParameters as BindCameraParameters;
 use crate::cxxbridge::ffi::LensModelType as BindLensModelType;
+use crate::cxxbridge::ffi::OptionParameters3deAnamorphicStdDeg4 as BindOptionParameters3deAnamorphicStdDeg4;
+use crate::cxxbridge::ffi::OptionParameters3deAnamorphicStdDeg4Rescaled as BindOptionParameters3deAnamorphicStdDeg4Rescaled;
 use crate::cxxbridge::ffi::OptionParameters3deClassic as BindOptionParameters3deClassic;
+use crate::cxxbridge::ffi::OptionParameters3deRadialStdDeg4 as BindOptionParameters3deRadialStdDeg4;
+use crate::cxxbridge::ffi::OptionParametersOpenCvBrownConrady as BindOptionParametersOpenCvBrownConrady;
 use crate::data::FrameNumber;
 use crate::data::FrameSize;
 use crate::data::HashValue64;
@@ -38,15 +43,14 @@ use crate::hash_float::HashableF64;
 use rustc_hash::FxHasher;
 use smallvec::SmallVec;
 use std::collections::HashMap;
+use std::collections::VecDeque;
 use std::convert::TryInto;
 use std::hash::Hash;
 use std::hash::Hasher;
+use std::sync::Mutex;
 
 // use crate::constants::MAX_LENS_PARAMETER_COUNT;
 // use crate::cxxbridge::ffi::OptionCameraParameters as BindOptionCameraParameters;
-// use crate::cxxbridge::ffi::OptionParameters3deAnamorphicStdDeg4 as BindOptionParameters3deAnamorphicStdDeg4;
-// use crate::cxxbridge::ffi::OptionParameters3deAnamorphicStdDeg4Rescaled as BindOptionParameters3deAnamorphicStdDeg4Rescaled;
-// use crate::cxxbridge::ffi::OptionParameters3deRadialStdDeg4 as BindOptionParameters3deRadialStdDeg4;
 
 impl BindLensModelType {
     fn parameter_size(&self) -> ParameterSize {
@@ -61,6 +65,9 @@ impl BindLensModelType {
             BindLensModelType::TdeAnamorphicStdDeg4Rescaled => {
                 PARAMETER_COUNT_3DE_ANAMORPHIC_STD_DEG4_RESCALED
             }
+            BindLensModelType::OpenCvBrownConrady => {
+                PARAMETER_COUNT_OPENCV_BROWN_CONRADY
+            }
             _ => 0,
         }
     }
@@ -124,6 +131,13 @@ fn set_parameter_block_values(
                 output_values[12] = input_values[12];
                 output_values[13] = input_values[13];
             }
+            BindLensModelType::OpenCvBrownConrady => {
+                output_values[0] = input_values[0];
+                output_values[1] = input_values[1];
+                output_values[2] = input_values[2];
+                output_values[3] = input_values[3];
+                output_values[4] = input_values[4];
+            }
             _ => (),
         },
         None => {
@@ -133,6 +147,50 @@ fn set_parameter_block_values(
     };
 }
 
+/// Resolve the stored parameter values for a layer/frame pair,
+/// clamping to the nearest stored frame the same way
+/// `layer_parameters_3de_classic` does. Returns `None` when
+/// `layer_num` is out of range or the layer's lens model does not
+/// match `lens_model_type`.
+fn resolve_layer_parameter_values<'a>(
+    layer_num: LayerIndex,
+    frame: FrameNumber,
+    lens_model_type: BindLensModelType,
+    layer_count: LayerSize,
+    layer_lens_model_types: &SmallVec<[BindLensModelType; 4]>,
+    layer_frame_range: &SmallVec<[(FrameNumber, FrameNumber); 4]>,
+    parameter_indices: &[(ParameterIndex, ParameterSize)],
+    parameter_block: &'a [f64],
+) -> Option<&'a [f64]> {
+    if layer_num >= layer_count {
+        return None;
+    }
+    let index = layer_num as usize;
+    if layer_lens_model_types[index] != lens_model_type {
+        return None;
+    }
+
+    let (start_frame, _end_frame) = layer_frame_range[index];
+    let frame_count = lens_frame_count(layer_num, layer_frame_range);
+
+    let mut frame_index: i32 = 0;
+    if frame_count > 1 {
+        frame_index = frame as i32 - start_frame as i32;
+    }
+    frame_index = std::cmp::max(frame_index, 0);
+    frame_index = std::cmp::min(frame_index, (frame_count - 1) as i32);
+
+    let (parameter_entry_count, _) =
+        count_parameters(layer_num, layer_frame_range, layer_lens_model_types);
+
+    let value_index: usize = parameter_entry_count + frame_index as usize;
+    let (parameter_index, parameter_size) = parameter_indices[value_index];
+
+    let index_start = parameter_index as usize;
+    let index_end = index_start + parameter_size as usize;
+    Some(&parameter_block[index_start..index_end])
+}
+
 fn lens_frame_count(
     layer_num: LayerIndex,
     layer_frame_range: &SmallVec<[(FrameNumber, FrameNumber); 4]>,
@@ -170,6 +228,188 @@ fn count_parameters(
     (total_parameter_count, total_parameter_value_count)
 }
 
+/// Which way to walk the layers of a `ShimDistortionLayers` stack.
+///
+/// Layers are stored top-to-bottom in the order needed to remove
+/// distortion from a point ("undistortion"). Applying distortion to
+/// an already-undistorted point ("redistortion") must walk the same
+/// layers in the opposite order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvaluationDirection {
+    /// Top-to-bottom (layer 0, 1, 2, ...). Removes distortion.
+    Undistort,
+    /// Bottom-to-top (layer N-1, N-2, ...). Applies distortion.
+    Redistort,
+}
+
+/// The camera parameters for a frame, together with the overscan
+/// scale factor needed to redistort without introducing black
+/// borders. See `ShimDistortionLayers::overscan_scale`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct ShimOverscanCameraParameters {
+    pub camera_parameters: BindCameraParameters,
+    pub overscan_scale: ShimOverscanScale,
+}
+
+/// The result of `ShimDistortionLayers::overscan_scale`: the
+/// estimated scale factor, plus whether every active layer's lens
+/// model was accounted for while computing it.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct ShimOverscanScale {
+    pub scale: f64,
+    /// `false` if any active layer uses a lens model whose radial
+    /// distortion `overscan_scale` does not model (currently only
+    /// `TdeClassic` and `OpenCvBrownConrady` are accounted for). In
+    /// that case `scale` treats the unmodeled layers as a
+    /// pass-through and may understate the overscan actually
+    /// needed.
+    pub is_complete: bool,
+}
+
+/// Apply the 3DE Classic model's radial polynomial to a
+/// lens-centered point, used by `overscan_scale` to estimate how
+/// much a frame expands under distortion.
+fn apply_classic_radial_distortion(
+    x: f64,
+    y: f64,
+    distortion: f64,
+    quartic_distortion: f64,
+) -> (f64, f64) {
+    let r2 = (x * x) + (y * y);
+    let factor = 1.0 + (distortion * r2) + (quartic_distortion * r2 * r2);
+    (x * factor, y * factor)
+}
+
+/// Apply the OpenCV Brown-Conrady radial-tangential distortion
+/// model to a normalized, lens-centered point:
+///
+/// x_d = x(1 + k1 r^2 + k2 r^4 + k3 r^6) + 2 p1 x y + p2 (r^2 + 2 x^2)
+/// y_d = y(1 + k1 r^2 + k2 r^4 + k3 r^6) + 2 p2 x y + p1 (r^2 + 2 y^2)
+///
+/// with r^2 = x^2 + y^2.
+fn apply_brown_conrady_distortion(
+    x: f64,
+    y: f64,
+    k1: f64,
+    k2: f64,
+    p1: f64,
+    p2: f64,
+    k3: f64,
+) -> (f64, f64) {
+    let r2 = (x * x) + (y * y);
+    let radial = 1.0 + (k1 * r2) + (k2 * r2 * r2) + (k3 * r2 * r2 * r2);
+    let x_d = (x * radial) + (2.0 * p1 * x * y) + (p2 * (r2 + (2.0 * x * x)));
+    let y_d = (y * radial) + (2.0 * p2 * x * y) + (p1 * (r2 + (2.0 * y * y)));
+    (x_d, y_d)
+}
+
+/// Default number of frames kept in a `ShimDistortionLayers`'
+/// per-frame evaluation cache.
+const DEFAULT_FRAME_EVALUATION_CACHE_CAPACITY: usize = 32;
+
+/// Cheaply derive a `ShimDistortionLayers` evaluation-cache key from
+/// `frame` alone. This is intentionally much cheaper than
+/// `ShimDistortionLayers::frame_hash`, which re-resolves every
+/// layer's decoded parameters (and, for `TdeClassic`, blends between
+/// two stored frames) to build its hash. `frame` is sufficient here
+/// because everything else the cached value depends on
+/// (`layer_lens_model_types`, `layer_frame_range`,
+/// `parameter_block`) is fixed for the lifetime of the instance.
+fn frame_cache_key(frame: FrameNumber) -> HashValue64 {
+    let mut s = FxHasher::default();
+    frame.hash(&mut s);
+    s.finish()
+}
+
+/// Hit/miss counters for a `ShimDistortionLayers`' evaluation
+/// cache. See `ShimDistortionLayers::cache_statistics`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ShimFrameEvaluationCacheStatistics {
+    pub hit_count: u64,
+    pub miss_count: u64,
+}
+
+/// A small bounded LRU cache from `frame_cache_key` to the resolved
+/// parameter values for that frame, so repeated evaluations of an
+/// unchanged frame do not need to re-read and re-pack
+/// `parameter_block`.
+#[derive(Debug)]
+struct FrameEvaluationCache {
+    capacity: usize,
+    values: HashMap<HashValue64, Vec<f64>>,
+    // Least-recently-used key is at the front.
+    recency: VecDeque<HashValue64>,
+    hit_count: u64,
+    miss_count: u64,
+}
+
+impl FrameEvaluationCache {
+    fn new(capacity: usize) -> FrameEvaluationCache {
+        FrameEvaluationCache {
+            capacity,
+            values: HashMap::new(),
+            recency: VecDeque::new(),
+            hit_count: 0,
+            miss_count: 0,
+        }
+    }
+
+    fn touch(&mut self, key: HashValue64) {
+        if let Some(position) =
+            self.recency.iter().position(|cached_key| *cached_key == key)
+        {
+            self.recency.remove(position);
+        }
+        self.recency.push_back(key);
+    }
+
+    fn get(&mut self, key: HashValue64) -> Option<Vec<f64>> {
+        match self.values.get(&key).cloned() {
+            Some(values) => {
+                self.touch(key);
+                self.hit_count += 1;
+                Some(values)
+            }
+            None => {
+                self.miss_count += 1;
+                None
+            }
+        }
+    }
+
+    fn insert(&mut self, key: HashValue64, values: Vec<f64>) {
+        if self.capacity == 0 {
+            return;
+        }
+        if !self.values.contains_key(&key)
+            && self.values.len() >= self.capacity
+        {
+            if let Some(lru_key) = self.recency.pop_front() {
+                self.values.remove(&lru_key);
+            }
+        }
+        self.values.insert(key, values);
+        self.touch(key);
+    }
+
+    fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity;
+        while self.values.len() > self.capacity {
+            match self.recency.pop_front() {
+                Some(lru_key) => {
+                    self.values.remove(&lru_key);
+                }
+                None => break,
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        self.values.clear();
+        self.recency.clear();
+    }
+}
+
 /// Represents "Layers" of (lens) distortion data.
 ///
 /// The layers are conceptually stacked top-to-bottom, so the zeroth
@@ -181,7 +421,7 @@ fn count_parameters(
 /// This data structure is intended for optimized lookup of
 /// precomputed read-only camera and lens distortion parameter
 /// data.
-#[derive(Debug, Clone, PartialEq, PartialOrd)]
+#[derive(Debug)]
 pub struct ShimDistortionLayers {
     // The lens_count is assumed to be most often 1, sometimes 2, and
     // in rare cases 3. Values of 4 or above do not seem practical.
@@ -205,6 +445,65 @@ pub struct ShimDistortionLayers {
 
     // A big block of parameter values that is indexed into.
     parameter_block: Vec<f64>,
+
+    // Memoizes resolved parameter values per frame, keyed by
+    // frame_cache_key() (derived from the frame number alone, not
+    // from frame_hash()), so repeated evaluation of the same
+    // unchanged frame is cheap.
+    // A Mutex (rather than a RefCell) is used because lookups
+    // happen through shared (&self) accessor methods and this type
+    // is evaluated from multiple threads during playback/solving.
+    eval_cache: Mutex<FrameEvaluationCache>,
+}
+
+impl Clone for ShimDistortionLayers {
+    fn clone(&self) -> ShimDistortionLayers {
+        // The memoized cache is derived data, not part of the
+        // logical value being cloned, so the clone starts with an
+        // empty cache of the same capacity (mirroring `from_parts`).
+        let capacity = self.cache_capacity();
+        ShimDistortionLayers {
+            layer_count: self.layer_count,
+            layer_lens_model_types: self.layer_lens_model_types.clone(),
+            layer_frame_range: self.layer_frame_range.clone(),
+            camera_parameters: self.camera_parameters,
+            parameter_indices: self.parameter_indices.clone(),
+            parameter_block: self.parameter_block.clone(),
+            eval_cache: Mutex::new(FrameEvaluationCache::new(capacity)),
+        }
+    }
+}
+
+impl PartialEq for ShimDistortionLayers {
+    fn eq(&self, other: &Self) -> bool {
+        self.layer_count == other.layer_count
+            && self.layer_lens_model_types == other.layer_lens_model_types
+            && self.layer_frame_range == other.layer_frame_range
+            && self.camera_parameters == other.camera_parameters
+            && self.parameter_indices == other.parameter_indices
+            && self.parameter_block == other.parameter_block
+    }
+}
+
+impl PartialOrd for ShimDistortionLayers {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        (
+            self.layer_count,
+            &self.layer_lens_model_types,
+            &self.layer_frame_range,
+            &self.camera_parameters,
+            &self.parameter_indices,
+            &self.parameter_block,
+        )
+            .partial_cmp(&(
+                other.layer_count,
+                &other.layer_lens_model_types,
+                &other.layer_frame_range,
+                &other.camera_parameters,
+                &other.parameter_indices,
+                &other.parameter_block,
+            ))
+    }
 }
 
 pub fn shim_create_distortion_layers_box() -> Box<ShimDistortionLayers> {
@@ -220,6 +519,9 @@ impl ShimDistortionLayers {
             camera_parameters: BindCameraParameters::default(),
             parameter_indices: Vec::new(),
             parameter_block: Vec::new(),
+            eval_cache: Mutex::new(FrameEvaluationCache::new(
+                DEFAULT_FRAME_EVALUATION_CACHE_CAPACITY,
+            )),
         };
     }
 
@@ -336,6 +638,12 @@ impl ShimDistortionLayers {
             camera_parameters,
             parameter_indices: parameter_indices,
             parameter_block: parameter_block,
+            // A freshly built instance always starts with an empty
+            // cache, since the underlying parameters may differ
+            // from whatever was cached before.
+            eval_cache: Mutex::new(FrameEvaluationCache::new(
+                DEFAULT_FRAME_EVALUATION_CACHE_CAPACITY,
+            )),
         }
     }
 
@@ -436,6 +744,134 @@ impl ShimDistortionLayers {
                     curvature_y.hash(&mut s);
                     quartic_distortion.hash(&mut s);
                 }
+                BindLensModelType::TdeRadialStdDeg4 => {
+                    let option_parameters = self
+                        .layer_parameters_3de_radial_std_deg4(layer_num, frame);
+                    assert!(option_parameters.exists == true);
+                    let parameters = option_parameters.value;
+
+                    let degree2_distortion =
+                        HashableF64::new(parameters.degree2_distortion);
+                    let degree2_u = HashableF64::new(parameters.degree2_u);
+                    let degree2_v = HashableF64::new(parameters.degree2_v);
+                    let degree4_distortion =
+                        HashableF64::new(parameters.degree4_distortion);
+                    let degree4_u = HashableF64::new(parameters.degree4_u);
+                    let degree4_v = HashableF64::new(parameters.degree4_v);
+                    let cylindric_direction =
+                        HashableF64::new(parameters.cylindric_direction);
+                    let cylindric_bending =
+                        HashableF64::new(parameters.cylindric_bending);
+
+                    lens_model_type.hash(&mut s);
+                    degree2_distortion.hash(&mut s);
+                    degree2_u.hash(&mut s);
+                    degree2_v.hash(&mut s);
+                    degree4_distortion.hash(&mut s);
+                    degree4_u.hash(&mut s);
+                    degree4_v.hash(&mut s);
+                    cylindric_direction.hash(&mut s);
+                    cylindric_bending.hash(&mut s);
+                }
+                BindLensModelType::TdeAnamorphicStdDeg4 => {
+                    let option_parameters = self
+                        .layer_parameters_3de_anamorphic_std_deg4(
+                            layer_num, frame,
+                        );
+                    assert!(option_parameters.exists == true);
+                    let parameters = option_parameters.value;
+
+                    let degree2_cx02 = HashableF64::new(parameters.degree2_cx02);
+                    let degree2_cy02 = HashableF64::new(parameters.degree2_cy02);
+                    let degree2_cx22 = HashableF64::new(parameters.degree2_cx22);
+                    let degree2_cy22 = HashableF64::new(parameters.degree2_cy22);
+                    let degree4_cx04 = HashableF64::new(parameters.degree4_cx04);
+                    let degree4_cy04 = HashableF64::new(parameters.degree4_cy04);
+                    let degree4_cx24 = HashableF64::new(parameters.degree4_cx24);
+                    let degree4_cy24 = HashableF64::new(parameters.degree4_cy24);
+                    let degree4_cx44 = HashableF64::new(parameters.degree4_cx44);
+                    let degree4_cy44 = HashableF64::new(parameters.degree4_cy44);
+                    let lens_rotation =
+                        HashableF64::new(parameters.lens_rotation);
+                    let squeeze_x = HashableF64::new(parameters.squeeze_x);
+                    let squeeze_y = HashableF64::new(parameters.squeeze_y);
+
+                    lens_model_type.hash(&mut s);
+                    degree2_cx02.hash(&mut s);
+                    degree2_cy02.hash(&mut s);
+                    degree2_cx22.hash(&mut s);
+                    degree2_cy22.hash(&mut s);
+                    degree4_cx04.hash(&mut s);
+                    degree4_cy04.hash(&mut s);
+                    degree4_cx24.hash(&mut s);
+                    degree4_cy24.hash(&mut s);
+                    degree4_cx44.hash(&mut s);
+                    degree4_cy44.hash(&mut s);
+                    lens_rotation.hash(&mut s);
+                    squeeze_x.hash(&mut s);
+                    squeeze_y.hash(&mut s);
+                }
+                BindLensModelType::TdeAnamorphicStdDeg4Rescaled => {
+                    let option_parameters = self
+                        .layer_parameters_3de_anamorphic_std_deg4_rescaled(
+                            layer_num, frame,
+                        );
+                    assert!(option_parameters.exists == true);
+                    let parameters = option_parameters.value;
+
+                    let degree2_cx02 = HashableF64::new(parameters.degree2_cx02);
+                    let degree2_cy02 = HashableF64::new(parameters.degree2_cy02);
+                    let degree2_cx22 = HashableF64::new(parameters.degree2_cx22);
+                    let degree2_cy22 = HashableF64::new(parameters.degree2_cy22);
+                    let degree4_cx04 = HashableF64::new(parameters.degree4_cx04);
+                    let degree4_cy04 = HashableF64::new(parameters.degree4_cy04);
+                    let degree4_cx24 = HashableF64::new(parameters.degree4_cx24);
+                    let degree4_cy24 = HashableF64::new(parameters.degree4_cy24);
+                    let degree4_cx44 = HashableF64::new(parameters.degree4_cx44);
+                    let degree4_cy44 = HashableF64::new(parameters.degree4_cy44);
+                    let lens_rotation =
+                        HashableF64::new(parameters.lens_rotation);
+                    let squeeze_x = HashableF64::new(parameters.squeeze_x);
+                    let squeeze_y = HashableF64::new(parameters.squeeze_y);
+                    let rescale = HashableF64::new(parameters.rescale);
+
+                    lens_model_type.hash(&mut s);
+                    degree2_cx02.hash(&mut s);
+                    degree2_cy02.hash(&mut s);
+                    degree2_cx22.hash(&mut s);
+                    degree2_cy22.hash(&mut s);
+                    degree4_cx04.hash(&mut s);
+                    degree4_cy04.hash(&mut s);
+                    degree4_cx24.hash(&mut s);
+                    degree4_cy24.hash(&mut s);
+                    degree4_cx44.hash(&mut s);
+                    degree4_cy44.hash(&mut s);
+                    lens_rotation.hash(&mut s);
+                    squeeze_x.hash(&mut s);
+                    squeeze_y.hash(&mut s);
+                    rescale.hash(&mut s);
+                }
+                BindLensModelType::OpenCvBrownConrady => {
+                    let option_parameters = self
+                        .layer_parameters_opencv_brown_conrady(
+                            layer_num, frame,
+                        );
+                    assert!(option_parameters.exists == true);
+                    let parameters = option_parameters.value;
+
+                    let k1 = HashableF64::new(parameters.k1);
+                    let k2 = HashableF64::new(parameters.k2);
+                    let p1 = HashableF64::new(parameters.p1);
+                    let p2 = HashableF64::new(parameters.p2);
+                    let k3 = HashableF64::new(parameters.k3);
+
+                    lens_model_type.hash(&mut s);
+                    k1.hash(&mut s);
+                    k2.hash(&mut s);
+                    p1.hash(&mut s);
+                    p2.hash(&mut s);
+                    k3.hash(&mut s);
+                }
                 _ => {
                     panic!("Unsupported Lens Model Type: {:?}", lens_model_type)
                 }
@@ -454,6 +890,134 @@ impl ShimDistortionLayers {
         self.layer_count
     }
 
+    /// The layer indices in the order they must be evaluated for
+    /// the given `direction`.
+    pub fn layer_indices(
+        &self,
+        direction: EvaluationDirection,
+    ) -> SmallVec<[LayerIndex; 4]> {
+        let mut indices: SmallVec<[LayerIndex; 4]> =
+            (0..self.layer_count).collect();
+        if direction == EvaluationDirection::Redistort {
+            indices.reverse();
+        }
+        indices
+    }
+
+    /// Estimate the overscan scale factor needed to redistort a
+    /// frame without introducing black borders at `frame`.
+    ///
+    /// The four frame-buffer corners (derived from the camera's
+    /// film-back and lens-center offset) are pushed through the
+    /// undistortion layers, and the scale is the maximum ratio of
+    /// distorted to original corner radius. A corner that would
+    /// shrink under distortion does not require overscan, so the
+    /// result is never less than `1.0`.
+    ///
+    /// Only the `TdeClassic` model's radial terms and the
+    /// `OpenCvBrownConrady` model's radial and tangential terms are
+    /// accounted for; other lens models in the
+    /// stack are treated as a pass-through for this estimate, and
+    /// `is_complete` is `false` whenever one of those is active, so
+    /// callers can tell the estimate may be an understatement.
+    pub fn overscan_scale(&self, frame: FrameNumber) -> ShimOverscanScale {
+        let is_complete = self
+            .layer_indices(EvaluationDirection::Undistort)
+            .into_iter()
+            .all(|layer_num| {
+                matches!(
+                    self.layer_lens_model_type(layer_num),
+                    BindLensModelType::TdeClassic
+                        | BindLensModelType::OpenCvBrownConrady
+                )
+            });
+
+        let camera_parameters = self.camera_parameters();
+        let half_width = camera_parameters.film_back_width_cm * 0.5;
+        let half_height = camera_parameters.film_back_height_cm * 0.5;
+        let offset_x = camera_parameters.lens_center_offset_x_cm;
+        let offset_y = camera_parameters.lens_center_offset_y_cm;
+
+        let corners = [
+            (-half_width - offset_x, -half_height - offset_y),
+            (half_width - offset_x, -half_height - offset_y),
+            (-half_width - offset_x, half_height - offset_y),
+            (half_width - offset_x, half_height - offset_y),
+        ];
+
+        let mut max_scale: f64 = 1.0;
+        for (x0, y0) in corners {
+            let original_radius = ((x0 * x0) + (y0 * y0)).sqrt();
+            if original_radius <= 0.0 {
+                continue;
+            }
+
+            let mut x = x0;
+            let mut y = y0;
+            for layer_num in self.layer_indices(EvaluationDirection::Undistort)
+            {
+                match self.layer_lens_model_type(layer_num) {
+                    BindLensModelType::TdeClassic => {
+                        let option_parameters = self
+                            .layer_parameters_3de_classic(layer_num, frame);
+                        if option_parameters.exists {
+                            let parameters = option_parameters.value;
+                            let (nx, ny) = apply_classic_radial_distortion(
+                                x,
+                                y,
+                                parameters.distortion,
+                                parameters.quartic_distortion,
+                            );
+                            x = nx;
+                            y = ny;
+                        }
+                    }
+                    BindLensModelType::OpenCvBrownConrady => {
+                        let option_parameters = self
+                            .layer_parameters_opencv_brown_conrady(
+                                layer_num, frame,
+                            );
+                        if option_parameters.exists {
+                            let parameters = option_parameters.value;
+                            let (nx, ny) = apply_brown_conrady_distortion(
+                                x,
+                                y,
+                                parameters.k1,
+                                parameters.k2,
+                                parameters.p1,
+                                parameters.p2,
+                                parameters.k3,
+                            );
+                            x = nx;
+                            y = ny;
+                        }
+                    }
+                    _ => (),
+                }
+            }
+
+            let distorted_radius = ((x * x) + (y * y)).sqrt();
+            let scale = distorted_radius / original_radius;
+            if scale.is_finite() && scale > max_scale {
+                max_scale = scale;
+            }
+        }
+
+        ShimOverscanScale { scale: max_scale, is_complete }
+    }
+
+    /// The camera parameters for `frame`, paired with the overscan
+    /// scale needed to redistort without black borders.
+    pub fn camera_parameters_with_overscan(
+        &self,
+        frame: FrameNumber,
+    ) -> ShimOverscanCameraParameters {
+        ShimOverscanCameraParameters {
+            camera_parameters: self.camera_parameters(),
+            overscan_scale: self.overscan_scale(frame),
+        }
+    }
+
     pub fn layer_lens_model_type(&self, layer_num: u8) -> BindLensModelType {
         if layer_num >= self.layer_count {
             BindLensModelType::Uninitialized
@@ -469,63 +1033,87 @@ impl ShimDistortionLayers {
         layer_num: u8,
         frame: FrameNumber,
     ) -> BindOptionParameters3deClassic {
-        println!(
-            "layer_parameters_3de_classic: layer_num={} frame={}",
-            layer_num, frame
-        );
+        self.layer_parameters_3de_classic_at_frame(layer_num, frame as f64)
+    }
+
+    /// Evaluate the 3DE Classic parameters at a (possibly
+    /// fractional) frame.
+    ///
+    /// When the layer is static, or 'frame' lies outside the stored
+    /// range, this behaves exactly like
+    /// `layer_parameters_3de_classic` and returns the single
+    /// clamped block with no blending. Otherwise the two bracketing
+    /// integer frames are fetched and each parameter is linearly
+    /// interpolated between them, so motion-blur/retiming workflows
+    /// get smooth distortion without requiring a stored key at
+    /// every sub-frame.
+    pub fn layer_parameters_3de_classic_at_frame(
+        &self,
+        layer_num: u8,
+        frame: f64,
+    ) -> BindOptionParameters3deClassic {
         if layer_num >= self.layer_count {
-            println!("layer_parameters_3de_classic: 1");
             return BindOptionParameters3deClassic::new_as_none();
         }
         let index = layer_num as usize;
         if self.layer_lens_model_types[index] != BindLensModelType::TdeClassic {
-            println!("layer_parameters_3de_classic: 2");
             return BindOptionParameters3deClassic::new_as_none();
         }
 
-        let (start_frame, _end_frame) =
-            self.layer_frame_range[layer_num as usize];
+        let (start_frame, end_frame) = self.layer_frame_range[index];
         let frame_count = lens_frame_count(layer_num, &self.layer_frame_range);
 
-        let mut frame_index: i32 = 0;
-        if frame_count > 1 {
-            frame_index = frame as i32 - start_frame as i32;
-        }
-        frame_index = std::cmp::max(frame_index, 0);
-        frame_index = std::cmp::min(frame_index, (frame_count - 1) as i32);
-
-        let (_, parameter_value_count) = count_parameters(
+        let (parameter_entry_count, _) = count_parameters(
             layer_num,
             &self.layer_frame_range,
             &self.layer_lens_model_types,
         );
-        println!(
-            "layer_parameters_3de_classic: parameter_value_count: {}",
-            parameter_value_count
-        );
 
-        let index: usize = parameter_value_count + frame_index as usize;
-        println!("layer_parameters_3de_classic: index: {}", index);
+        let fetch = |frame_index: i32| -> &[f64] {
+            let value_index: usize =
+                parameter_entry_count + frame_index as usize;
+            let (parameter_index, parameter_size) =
+                self.parameter_indices[value_index];
+            let index_start = parameter_index as usize;
+            let index_end = index_start + parameter_size as usize;
+            &self.parameter_block[index_start..index_end]
+        };
 
-        let (parameter_index, parameter_size) = self.parameter_indices[index];
-        println!(
-            "layer_parameters_3de_classic: parameter_index: {}",
-            parameter_index
-        );
-        println!(
-            "layer_parameters_3de_classic: parameter_size: {}",
-            parameter_size
-        );
+        // Static layers, or frames outside the stored range, fall
+        // back to the single clamped block with no blending.
+        let in_range = frame >= start_frame as f64 && frame <= end_frame as f64;
+        if frame_count <= 1 || !in_range {
+            let mut i0 = (frame - start_frame as f64).floor() as i32;
+            i0 = std::cmp::max(i0, 0);
+            i0 = std::cmp::min(i0, (frame_count - 1) as i32);
+            let values = fetch(i0);
+            return BindOptionParameters3deClassic::new_as_some(
+                values[0], values[1], values[2], values[3], values[4],
+            );
+        }
+
+        let mut i0 = (frame - start_frame as f64).floor() as i32;
+        i0 = std::cmp::max(i0, 0);
+        i0 = std::cmp::min(i0, (frame_count - 1) as i32);
+        let i1 = std::cmp::min(i0 + 1, (frame_count - 1) as i32);
+
+        if i0 == i1 {
+            let values = fetch(i0);
+            return BindOptionParameters3deClassic::new_as_some(
+                values[0], values[1], values[2], values[3], values[4],
+            );
+        }
 
-        let index_start = parameter_index as usize;
-        let index_end = parameter_index as usize + parameter_size as usize;
-        let values = &self.parameter_block[index_start..index_end];
+        let t = frame - frame.floor();
+        let v0 = fetch(i0);
+        let v1 = fetch(i1);
+
+        let distortion = v0[0] * (1.0 - t) + v1[0] * t;
+        let anamorphic_squeeze = v0[1] * (1.0 - t) + v1[1] * t;
+        let curvature_x = v0[2] * (1.0 - t) + v1[2] * t;
+        let curvature_y = v0[3] * (1.0 - t) + v1[3] * t;
+        let quartic_distortion = v0[4] * (1.0 - t) + v1[4] * t;
 
-        let distortion = values[0];
-        let anamorphic_squeeze = values[1];
-        let curvature_x = values[2];
-        let curvature_y = values[3];
-        let quartic_distortion = values[4];
         BindOptionParameters3deClassic::new_as_some(
             distortion,
             anamorphic_squeeze,
@@ -535,7 +1123,564 @@ impl ShimDistortionLayers {
         )
     }
 
+    /// When a 'frame' outside the frame range is requested, the
+    /// returned values come from the first or last frame.
+    pub fn layer_parameters_3de_radial_std_deg4(
+        &self,
+        layer_num: u8,
+        frame: FrameNumber,
+    ) -> BindOptionParameters3deRadialStdDeg4 {
+        match resolve_layer_parameter_values(
+            layer_num,
+            frame,
+            BindLensModelType::TdeRadialStdDeg4,
+            self.layer_count,
+            &self.layer_lens_model_types,
+            &self.layer_frame_range,
+            &self.parameter_indices,
+            &self.parameter_block,
+        ) {
+            Some(values) => BindOptionParameters3deRadialStdDeg4::new_as_some(
+                values[0], values[1], values[2], values[3], values[4],
+                values[5], values[6], values[7],
+            ),
+            None => BindOptionParameters3deRadialStdDeg4::new_as_none(),
+        }
+    }
+
+    /// When a 'frame' outside the frame range is requested, the
+    /// returned values come from the first or last frame.
+    pub fn layer_parameters_3de_anamorphic_std_deg4(
+        &self,
+        layer_num: u8,
+        frame: FrameNumber,
+    ) -> BindOptionParameters3deAnamorphicStdDeg4 {
+        match resolve_layer_parameter_values(
+            layer_num,
+            frame,
+            BindLensModelType::TdeAnamorphicStdDeg4,
+            self.layer_count,
+            &self.layer_lens_model_types,
+            &self.layer_frame_range,
+            &self.parameter_indices,
+            &self.parameter_block,
+        ) {
+            Some(values) => BindOptionParameters3deAnamorphicStdDeg4::new_as_some(
+                values[0], values[1], values[2], values[3], values[4],
+                values[5], values[6], values[7], values[8], values[9],
+                values[10], values[11], values[12],
+            ),
+            None => BindOptionParameters3deAnamorphicStdDeg4::new_as_none(),
+        }
+    }
+
+    /// When a 'frame' outside the frame range is requested, the
+    /// returned values come from the first or last frame.
+    pub fn layer_parameters_3de_anamorphic_std_deg4_rescaled(
+        &self,
+        layer_num: u8,
+        frame: FrameNumber,
+    ) -> BindOptionParameters3deAnamorphicStdDeg4Rescaled {
+        match resolve_layer_parameter_values(
+            layer_num,
+            frame,
+            BindLensModelType::TdeAnamorphicStdDeg4Rescaled,
+            self.layer_count,
+            &self.layer_lens_model_types,
+            &self.layer_frame_range,
+            &self.parameter_indices,
+            &self.parameter_block,
+        ) {
+            Some(values) => {
+                BindOptionParameters3deAnamorphicStdDeg4Rescaled::new_as_some(
+                    values[0], values[1], values[2], values[3], values[4],
+                    values[5], values[6], values[7], values[8], values[9],
+                    values[10], values[11], values[12], values[13],
+                )
+            }
+            None => {
+                BindOptionParameters3deAnamorphicStdDeg4Rescaled::new_as_none()
+            }
+        }
+    }
+
+    /// When a 'frame' outside the frame range is requested, the
+    /// returned values come from the first or last frame.
+    pub fn layer_parameters_opencv_brown_conrady(
+        &self,
+        layer_num: u8,
+        frame: FrameNumber,
+    ) -> BindOptionParametersOpenCvBrownConrady {
+        match resolve_layer_parameter_values(
+            layer_num,
+            frame,
+            BindLensModelType::OpenCvBrownConrady,
+            self.layer_count,
+            &self.layer_lens_model_types,
+            &self.layer_frame_range,
+            &self.parameter_indices,
+            &self.parameter_block,
+        ) {
+            Some(values) => BindOptionParametersOpenCvBrownConrady::new_as_some(
+                values[0], values[1], values[2], values[3], values[4],
+            ),
+            None => BindOptionParametersOpenCvBrownConrady::new_as_none(),
+        }
+    }
+
+    /// Resolve every layer's parameter values for `frame`,
+    /// concatenated in layer order.
+    fn collect_frame_parameter_values(&self, frame: FrameNumber) -> Vec<f64> {
+        let mut values = Vec::new();
+        for layer_num in 0..self.layer_count {
+            let lens_model_type = self.layer_lens_model_type(layer_num);
+            if let Some(layer_values) = resolve_layer_parameter_values(
+                layer_num,
+                frame,
+                lens_model_type,
+                self.layer_count,
+                &self.layer_lens_model_types,
+                &self.layer_frame_range,
+                &self.parameter_indices,
+                &self.parameter_block,
+            ) {
+                values.extend_from_slice(layer_values);
+            }
+        }
+        values
+    }
+
+    /// Resolve every layer's parameter values for `frame`, the same
+    /// as `collect_frame_parameter_values`, but memoized so repeated
+    /// calls for an unchanged frame skip re-reading `parameter_block`.
+    ///
+    /// The cache key is derived from `frame` alone, not `frame_hash`:
+    /// `layer_lens_model_types`, `layer_frame_range` and
+    /// `parameter_block` are fixed once this `ShimDistortionLayers`
+    /// is constructed, so `frame` is already enough to identify a
+    /// cache entry. Using `frame_hash` here would re-run the
+    /// per-model decoded accessors (including `TdeClassic`'s
+    /// sub-frame blending) just to compute the key, defeating the
+    /// point of caching.
+    pub fn evaluate_frame_cached(&self, frame: FrameNumber) -> Vec<f64> {
+        let key = frame_cache_key(frame);
+        if let Some(values) =
+            self.eval_cache.lock().unwrap().get(key)
+        {
+            return values;
+        }
+        let values = self.collect_frame_parameter_values(frame);
+        self.eval_cache.lock().unwrap().insert(key, values.clone());
+        values
+    }
+
+    /// Set the number of frames kept in the per-frame evaluation
+    /// cache, evicting least-recently-used entries if the cache is
+    /// currently larger than the new capacity.
+    pub fn set_cache_capacity(&self, capacity: usize) {
+        self.eval_cache.lock().unwrap().set_capacity(capacity);
+    }
+
+    /// The current capacity of the per-frame evaluation cache.
+    pub fn cache_capacity(&self) -> usize {
+        self.eval_cache.lock().unwrap().capacity
+    }
+
+    /// Hit/miss counters for the per-frame evaluation cache,
+    /// accumulated since this `ShimDistortionLayers` was created.
+    pub fn cache_statistics(&self) -> ShimFrameEvaluationCacheStatistics {
+        let cache = self.eval_cache.lock().unwrap();
+        ShimFrameEvaluationCacheStatistics {
+            hit_count: cache.hit_count,
+            miss_count: cache.miss_count,
+        }
+    }
+
+    /// Drop all cached frame evaluations. The hit/miss counters are
+    /// left untouched.
+    pub fn invalidate_cache(&self) {
+        self.eval_cache.lock().unwrap().clear();
+    }
+
     pub fn as_string(&self) -> String {
         format!("{:#?}", self).to_string()
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use smallvec::smallvec;
+
+    fn build_layers(
+        layer_types: SmallVec<[BindLensModelType; 4]>,
+        layer_frame_range: SmallVec<[(FrameNumber, FrameNumber); 4]>,
+        lens_parameters: HashMap<(LayerIndex, FrameNumber), ParameterBlock>,
+    ) -> ShimDistortionLayers {
+        let layer_count = layer_types.len() as LayerSize;
+        ShimDistortionLayers::from_parts(
+            layer_count,
+            &layer_types,
+            &layer_frame_range,
+            BindCameraParameters::default(),
+            &lens_parameters,
+        )
+    }
+
+    #[test]
+    fn multi_layer_accessors_resolve_independently() {
+        let layer_types = smallvec![
+            BindLensModelType::TdeClassic,
+            BindLensModelType::TdeRadialStdDeg4,
+            BindLensModelType::TdeAnamorphicStdDeg4,
+        ];
+        let layer_frame_range = smallvec![
+            (STATIC_FRAME_NUMBER, STATIC_FRAME_NUMBER),
+            (STATIC_FRAME_NUMBER, STATIC_FRAME_NUMBER),
+            (STATIC_FRAME_NUMBER, STATIC_FRAME_NUMBER),
+        ];
+        let mut lens_parameters = HashMap::new();
+        lens_parameters
+            .insert((0, STATIC_FRAME_NUMBER), vec![0.1, 0.2, 0.3, 0.4, 0.5]);
+        lens_parameters.insert(
+            (1, STATIC_FRAME_NUMBER),
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0],
+        );
+        lens_parameters.insert(
+            (2, STATIC_FRAME_NUMBER),
+            vec![
+                10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0, 17.0, 18.0, 19.0,
+                20.0, 21.0, 22.0,
+            ],
+        );
+
+        let layers =
+            build_layers(layer_types, layer_frame_range, lens_parameters);
+
+        // Layer 0 (first in the stack) resolves fine on its own.
+        let layer0 =
+            layers.layer_parameters_3de_classic(0, STATIC_FRAME_NUMBER);
+        assert!(layer0.exists);
+        assert_eq!(layer0.value.distortion, 0.1);
+
+        // Layers 1 and 2 must resolve independently of layer 0's
+        // parameter count, not panic or read layer 0's values.
+        let layer1 = layers
+            .layer_parameters_3de_radial_std_deg4(1, STATIC_FRAME_NUMBER);
+        assert!(layer1.exists);
+        assert_eq!(layer1.value.degree2_distortion, 1.0);
+        assert_eq!(layer1.value.cylindric_bending, 8.0);
+
+        let layer2 = layers
+            .layer_parameters_3de_anamorphic_std_deg4(2, STATIC_FRAME_NUMBER);
+        assert!(layer2.exists);
+        assert_eq!(layer2.value.degree2_cx02, 10.0);
+        assert_eq!(layer2.value.squeeze_y, 22.0);
+    }
+
+    #[test]
+    fn classic_subframe_interpolation_blends_between_keyed_frames() {
+        let layer_types = smallvec![BindLensModelType::TdeClassic];
+        let layer_frame_range = smallvec![(10, 20)];
+        let mut lens_parameters = HashMap::new();
+        for frame in 10..=20 {
+            lens_parameters
+                .insert((0, frame), vec![frame as f64, 0.0, 0.0, 0.0, 0.0]);
+        }
+        let layers =
+            build_layers(layer_types, layer_frame_range, lens_parameters);
+
+        let exact = layers.layer_parameters_3de_classic_at_frame(0, 12.0);
+        assert_eq!(exact.value.distortion, 12.0);
+
+        let half = layers.layer_parameters_3de_classic_at_frame(0, 12.5);
+        assert_eq!(half.value.distortion, 12.5);
+    }
+
+    #[test]
+    fn classic_subframe_interpolation_clamps_outside_the_frame_range() {
+        let layer_types = smallvec![BindLensModelType::TdeClassic];
+        let layer_frame_range = smallvec![(10, 20)];
+        let mut lens_parameters = HashMap::new();
+        for frame in 10..=20 {
+            lens_parameters
+                .insert((0, frame), vec![frame as f64, 0.0, 0.0, 0.0, 0.0]);
+        }
+        let layers =
+            build_layers(layer_types, layer_frame_range, lens_parameters);
+
+        // A frame before the range must return the first block
+        // unchanged, not a blend with an unrelated fractional part.
+        let before_range =
+            layers.layer_parameters_3de_classic_at_frame(0, 3.5);
+        assert_eq!(before_range.value.distortion, 10.0);
+
+        // Likewise for a frame after the range.
+        let after_range =
+            layers.layer_parameters_3de_classic_at_frame(0, 25.5);
+        assert_eq!(after_range.value.distortion, 20.0);
+    }
+
+    #[test]
+    fn classic_static_layer_never_blends() {
+        let layer_types = smallvec![BindLensModelType::TdeClassic];
+        let layer_frame_range =
+            smallvec![(STATIC_FRAME_NUMBER, STATIC_FRAME_NUMBER)];
+        let mut lens_parameters = HashMap::new();
+        lens_parameters
+            .insert((0, STATIC_FRAME_NUMBER), vec![7.0, 0.0, 0.0, 0.0, 0.0]);
+        let layers =
+            build_layers(layer_types, layer_frame_range, lens_parameters);
+
+        let result = layers.layer_parameters_3de_classic_at_frame(0, 42.75);
+        assert_eq!(result.value.distortion, 7.0);
+    }
+
+    fn build_layers_with_camera(
+        layer_types: SmallVec<[BindLensModelType; 4]>,
+        layer_frame_range: SmallVec<[(FrameNumber, FrameNumber); 4]>,
+        lens_parameters: HashMap<(LayerIndex, FrameNumber), ParameterBlock>,
+        camera_parameters: BindCameraParameters,
+    ) -> ShimDistortionLayers {
+        let layer_count = layer_types.len() as LayerSize;
+        ShimDistortionLayers::from_parts(
+            layer_count,
+            &layer_types,
+            &layer_frame_range,
+            camera_parameters,
+            &lens_parameters,
+        )
+    }
+
+    #[test]
+    fn layer_indices_is_reversed_for_redistort() {
+        let layer_types = smallvec![
+            BindLensModelType::TdeClassic,
+            BindLensModelType::TdeRadialStdDeg4,
+            BindLensModelType::TdeAnamorphicStdDeg4,
+        ];
+        let layer_frame_range = smallvec![
+            (STATIC_FRAME_NUMBER, STATIC_FRAME_NUMBER),
+            (STATIC_FRAME_NUMBER, STATIC_FRAME_NUMBER),
+            (STATIC_FRAME_NUMBER, STATIC_FRAME_NUMBER),
+        ];
+        let mut lens_parameters = HashMap::new();
+        lens_parameters.insert((0, STATIC_FRAME_NUMBER), vec![0.0; 5]);
+        lens_parameters.insert((1, STATIC_FRAME_NUMBER), vec![0.0; 8]);
+        lens_parameters.insert((2, STATIC_FRAME_NUMBER), vec![0.0; 13]);
+        let layers =
+            build_layers(layer_types, layer_frame_range, lens_parameters);
+
+        assert_eq!(
+            layers
+                .layer_indices(EvaluationDirection::Undistort)
+                .into_vec(),
+            vec![0, 1, 2]
+        );
+        assert_eq!(
+            layers
+                .layer_indices(EvaluationDirection::Redistort)
+                .into_vec(),
+            vec![2, 1, 0]
+        );
+    }
+
+    #[test]
+    fn overscan_scale_is_a_passthrough_with_no_distortion() {
+        let layer_types = smallvec![BindLensModelType::TdeClassic];
+        let layer_frame_range =
+            smallvec![(STATIC_FRAME_NUMBER, STATIC_FRAME_NUMBER)];
+        let mut lens_parameters = HashMap::new();
+        lens_parameters.insert(
+            (0, STATIC_FRAME_NUMBER),
+            vec![0.0, 0.0, 0.0, 0.0, 0.0],
+        );
+        let camera_parameters = BindCameraParameters {
+            focal_length_cm: 5.0,
+            film_back_width_cm: 3.6,
+            film_back_height_cm: 2.4,
+            pixel_aspect: 1.0,
+            lens_center_offset_x_cm: 0.0,
+            lens_center_offset_y_cm: 0.0,
+        };
+        let layers = build_layers_with_camera(
+            layer_types,
+            layer_frame_range,
+            lens_parameters,
+            camera_parameters,
+        );
+
+        let result = layers.overscan_scale(STATIC_FRAME_NUMBER);
+        assert_eq!(result.scale, 1.0);
+        assert!(result.is_complete);
+    }
+
+    #[test]
+    fn overscan_scale_grows_with_positive_radial_distortion() {
+        let layer_types = smallvec![BindLensModelType::TdeClassic];
+        let layer_frame_range =
+            smallvec![(STATIC_FRAME_NUMBER, STATIC_FRAME_NUMBER)];
+        let mut lens_parameters = HashMap::new();
+        lens_parameters.insert(
+            (0, STATIC_FRAME_NUMBER),
+            vec![0.5, 0.0, 0.0, 0.0, 0.0],
+        );
+        let camera_parameters = BindCameraParameters {
+            focal_length_cm: 5.0,
+            film_back_width_cm: 3.6,
+            film_back_height_cm: 2.4,
+            pixel_aspect: 1.0,
+            lens_center_offset_x_cm: 0.0,
+            lens_center_offset_y_cm: 0.0,
+        };
+        let layers = build_layers_with_camera(
+            layer_types,
+            layer_frame_range,
+            lens_parameters,
+            camera_parameters,
+        );
+
+        let result = layers.overscan_scale(STATIC_FRAME_NUMBER);
+        assert!(result.scale > 1.0);
+        assert!(result.is_complete);
+    }
+
+    #[test]
+    fn overscan_scale_flags_incomplete_coverage_for_unmodeled_lens_models() {
+        let layer_types = smallvec![BindLensModelType::TdeRadialStdDeg4];
+        let layer_frame_range =
+            smallvec![(STATIC_FRAME_NUMBER, STATIC_FRAME_NUMBER)];
+        let mut lens_parameters = HashMap::new();
+        lens_parameters.insert(
+            (0, STATIC_FRAME_NUMBER),
+            vec![0.5, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+        );
+        let camera_parameters = BindCameraParameters {
+            focal_length_cm: 5.0,
+            film_back_width_cm: 3.6,
+            film_back_height_cm: 2.4,
+            pixel_aspect: 1.0,
+            lens_center_offset_x_cm: 0.0,
+            lens_center_offset_y_cm: 0.0,
+        };
+        let layers = build_layers_with_camera(
+            layer_types,
+            layer_frame_range,
+            lens_parameters,
+            camera_parameters,
+        );
+
+        let result = layers.overscan_scale(STATIC_FRAME_NUMBER);
+        assert!(!result.is_complete);
+        assert_eq!(result.scale, 1.0);
+    }
+
+    #[test]
+    fn brown_conrady_accessor_round_trips_parameters() {
+        let layer_types = smallvec![BindLensModelType::OpenCvBrownConrady];
+        let layer_frame_range =
+            smallvec![(STATIC_FRAME_NUMBER, STATIC_FRAME_NUMBER)];
+        let mut lens_parameters = HashMap::new();
+        lens_parameters.insert(
+            (0, STATIC_FRAME_NUMBER),
+            vec![0.1, 0.01, 0.001, 0.002, 0.0001],
+        );
+        let layers =
+            build_layers(layer_types, layer_frame_range, lens_parameters);
+
+        let result = layers
+            .layer_parameters_opencv_brown_conrady(0, STATIC_FRAME_NUMBER);
+        assert!(result.exists);
+        assert_eq!(result.value.k1, 0.1);
+        assert_eq!(result.value.k2, 0.01);
+        assert_eq!(result.value.p1, 0.001);
+        assert_eq!(result.value.p2, 0.002);
+        assert_eq!(result.value.k3, 0.0001);
+    }
+
+    #[test]
+    fn brown_conrady_distortion_matches_the_documented_equations() {
+        let (x_d, y_d) = apply_brown_conrady_distortion(
+            0.1, 0.2, 0.05, 0.01, 0.002, 0.003, 0.0005,
+        );
+
+        let r2: f64 = (0.1 * 0.1) + (0.2 * 0.2);
+        let radial =
+            1.0 + (0.05 * r2) + (0.01 * r2 * r2) + (0.0005 * r2 * r2 * r2);
+        let expected_x =
+            (0.1 * radial) + (2.0 * 0.002 * 0.1 * 0.2) + (0.003 * (r2 + 2.0 * 0.1 * 0.1));
+        let expected_y =
+            (0.2 * radial) + (2.0 * 0.003 * 0.1 * 0.2) + (0.002 * (r2 + 2.0 * 0.2 * 0.2));
+
+        assert!((x_d - expected_x).abs() < 1e-12);
+        assert!((y_d - expected_y).abs() < 1e-12);
+    }
+
+    #[test]
+    fn brown_conrady_with_zero_parameters_is_a_passthrough() {
+        let (x_d, y_d) =
+            apply_brown_conrady_distortion(0.3, -0.4, 0.0, 0.0, 0.0, 0.0, 0.0);
+        assert_eq!(x_d, 0.3);
+        assert_eq!(y_d, -0.4);
+    }
+
+    fn build_three_frame_layers() -> ShimDistortionLayers {
+        let layer_types = smallvec![BindLensModelType::TdeClassic];
+        let layer_frame_range = smallvec![(1, 3)];
+        let mut lens_parameters = HashMap::new();
+        lens_parameters.insert((0, 1), vec![1.0, 0.0, 0.0, 0.0, 0.0]);
+        lens_parameters.insert((0, 2), vec![2.0, 0.0, 0.0, 0.0, 0.0]);
+        lens_parameters.insert((0, 3), vec![3.0, 0.0, 0.0, 0.0, 0.0]);
+        build_layers(layer_types, layer_frame_range, lens_parameters)
+    }
+
+    #[test]
+    fn evaluate_frame_cached_tracks_hits_and_misses() {
+        let layers = build_three_frame_layers();
+
+        let _ = layers.evaluate_frame_cached(1);
+        let after_miss = layers.cache_statistics();
+        assert_eq!(after_miss.miss_count, 1);
+        assert_eq!(after_miss.hit_count, 0);
+
+        let _ = layers.evaluate_frame_cached(1);
+        let after_hit = layers.cache_statistics();
+        assert_eq!(after_hit.miss_count, 1);
+        assert_eq!(after_hit.hit_count, 1);
+    }
+
+    #[test]
+    fn evaluate_frame_cached_evicts_the_least_recently_used_entry() {
+        let layers = build_three_frame_layers();
+        layers.set_cache_capacity(2);
+        assert_eq!(layers.cache_capacity(), 2);
+
+        let _ = layers.evaluate_frame_cached(1); // miss, cache: [1]
+        let _ = layers.evaluate_frame_cached(2); // miss, cache: [1, 2]
+        let _ = layers.evaluate_frame_cached(3); // miss, evicts 1, cache: [2, 3]
+        assert_eq!(layers.cache_statistics().miss_count, 3);
+
+        // Frame 1 was evicted, so re-evaluating it is a miss again.
+        let _ = layers.evaluate_frame_cached(1);
+        assert_eq!(layers.cache_statistics().miss_count, 4);
+
+        // Frame 3 is still cached.
+        let _ = layers.evaluate_frame_cached(3);
+        assert_eq!(layers.cache_statistics().hit_count, 1);
+    }
+
+    #[test]
+    fn invalidate_cache_clears_entries_but_keeps_the_counters() {
+        let layers = build_three_frame_layers();
+
+        let _ = layers.evaluate_frame_cached(1);
+        let _ = layers.evaluate_frame_cached(1);
+        assert_eq!(layers.cache_statistics().hit_count, 1);
+
+        layers.invalidate_cache();
+        let _ = layers.evaluate_frame_cached(1);
+        let stats = layers.cache_statistics();
+        assert_eq!(stats.hit_count, 1);
+        assert_eq!(stats.miss_count, 2);
+    }
 }
\ No newline at end of file